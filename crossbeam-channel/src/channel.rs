@@ -12,6 +12,7 @@ use counter;
 use err::{RecvError, RecvTimeoutError, SendError, SendTimeoutError, TryRecvError, TrySendError};
 use flavors;
 use select::{Operation, SelectHandle, Token};
+use utils;
 
 /// Creates a channel of unbounded capacity.
 ///
@@ -431,8 +432,52 @@ impl<T> Sender<T> {
     /// );
     /// ```
     pub fn send_timeout(&self, msg: T, timeout: Duration) -> Result<(), SendTimeoutError<T>> {
-        let deadline = Instant::now() + timeout;
+        match utils::convert_timeout_to_deadline(timeout) {
+            Some(deadline) => self.send_deadline(msg, deadline),
+            None => self.send(msg).map_err(SendTimeoutError::from),
+        }
+    }
 
+    /// Waits for a message to be sent into the channel, but only until a given deadline.
+    ///
+    /// If the channel is full and not disconnected, this call will block until the send operation
+    /// can proceed or the operation times out. If the channel becomes disconnected, this call will
+    /// wake up and return an error. The returned error contains the original message.
+    ///
+    /// If called on a zero-capacity channel, this method will wait for a receive operation to
+    /// appear on the other side of the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::{Duration, Instant};
+    /// use crossbeam_channel::{bounded, SendTimeoutError};
+    ///
+    /// let (s, r) = bounded(0);
+    ///
+    /// thread::spawn(move || {
+    ///     thread::sleep(Duration::from_secs(1));
+    ///     assert_eq!(r.recv(), Ok(2));
+    ///     drop(r);
+    /// });
+    ///
+    /// let now = Instant::now();
+    ///
+    /// assert_eq!(
+    ///     s.send_deadline(1, now + Duration::from_millis(500)),
+    ///     Err(SendTimeoutError::Timeout(1)),
+    /// );
+    /// assert_eq!(
+    ///     s.send_deadline(2, now + Duration::from_millis(1500)),
+    ///     Ok(()),
+    /// );
+    /// assert_eq!(
+    ///     s.send_deadline(3, now + Duration::from_millis(2000)),
+    ///     Err(SendTimeoutError::Disconnected(3)),
+    /// );
+    /// ```
+    pub fn send_deadline(&self, msg: T, deadline: Instant) -> Result<(), SendTimeoutError<T>> {
         match &self.flavor {
             SenderFlavor::Array(chan) => chan.send(msg, Some(deadline)),
             SenderFlavor::List(chan) => chan.send(msg, Some(deadline)),
@@ -440,6 +485,44 @@ impl<T> Sender<T> {
         }
     }
 
+    /// Sends all messages from an iterator into the channel, blocking on each one in turn.
+    ///
+    /// Each message is sent as if by [`send`], so on a zero-capacity channel every message waits
+    /// for its own receive operation. If the channel becomes disconnected, the error contains the
+    /// message that couldn't be sent together with the rest of the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_channel::unbounded;
+    ///
+    /// let (s, r) = unbounded();
+    /// assert!(s.send_iter(vec![1, 2]).is_ok());
+    /// assert_eq!(r.recv(), Ok(1));
+    /// assert_eq!(r.recv(), Ok(2));
+    ///
+    /// drop(r);
+    /// let (msg, rest) = s.send_iter(vec![3, 4]).unwrap_err().into_inner();
+    /// assert_eq!(msg, 3);
+    /// assert_eq!(rest.collect::<Vec<_>>(), [4]);
+    /// ```
+    ///
+    /// [`send`]: struct.Sender.html#method.send
+    pub fn send_iter<I>(&self, iter: I) -> Result<(), SendError<(T, I::IntoIter)>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+
+        while let Some(msg) = iter.next() {
+            if let Err(SendError(msg)) = self.send(msg) {
+                return Err(SendError((msg, iter)));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns `true` if the channel is empty.
     ///
     /// Note: Zero-capacity channels are always empty.
@@ -585,7 +668,17 @@ impl<T> Clone for Sender<T> {
 
 impl<T> fmt::Debug for Sender<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.pad("Sender { .. }")
+        let is_disconnected = match &self.flavor {
+            SenderFlavor::Array(chan) => chan.is_disconnected(),
+            SenderFlavor::List(chan) => chan.is_disconnected(),
+            SenderFlavor::Zero(chan) => chan.is_disconnected(),
+        };
+
+        f.debug_struct("Sender")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity())
+            .field("is_disconnected", &is_disconnected)
+            .finish()
     }
 }
 
@@ -782,14 +875,60 @@ impl<T> Receiver<T> {
     /// );
     /// ```
     pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
-        let deadline = Instant::now() + timeout;
+        match utils::convert_timeout_to_deadline(timeout) {
+            Some(deadline) => self.recv_deadline(deadline),
+            None => self.recv().map_err(RecvTimeoutError::from),
+        }
+    }
+
+    /// Waits for a message to be received from the channel, but only before a given deadline.
+    ///
+    /// If the channel is empty and not disconnected, this call will block until the receive
+    /// operation can proceed or the operation times out. If the channel is empty and becomes
+    /// disconnected, this call will wake up and return an error.
+    ///
+    /// If called on a zero-capacity channel, this method will wait for a send operation to appear
+    /// on the other side of the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::{Duration, Instant};
+    /// use crossbeam_channel::{unbounded, RecvTimeoutError};
+    ///
+    /// let (s, r) = unbounded();
+    ///
+    /// thread::spawn(move || {
+    ///     thread::sleep(Duration::from_secs(1));
+    ///     s.send(5).unwrap();
+    ///     drop(s);
+    /// });
+    ///
+    /// let now = Instant::now();
+    ///
+    /// assert_eq!(
+    ///     r.recv_deadline(now + Duration::from_millis(500)),
+    ///     Err(RecvTimeoutError::Timeout),
+    /// );
+    /// assert_eq!(
+    ///     r.recv_deadline(now + Duration::from_millis(1500)),
+    ///     Ok(5),
+    /// );
+    /// assert_eq!(
+    ///     r.recv_deadline(now + Duration::from_millis(2000)),
+    ///     Err(RecvTimeoutError::Disconnected),
+    /// );
+    /// ```
+    pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        let deadline = Some(deadline);
 
         match &self.flavor {
-            ReceiverFlavor::Array(chan) => chan.recv(Some(deadline)),
-            ReceiverFlavor::List(chan) => chan.recv(Some(deadline)),
-            ReceiverFlavor::Zero(chan) => chan.recv(Some(deadline)),
+            ReceiverFlavor::Array(chan) => chan.recv(deadline),
+            ReceiverFlavor::List(chan) => chan.recv(deadline),
+            ReceiverFlavor::Zero(chan) => chan.recv(deadline),
             ReceiverFlavor::After(chan) => {
-                let msg = chan.recv(Some(deadline));
+                let msg = chan.recv(deadline);
                 unsafe {
                     mem::transmute_copy::<
                         Result<Instant, RecvTimeoutError>,
@@ -798,7 +937,7 @@ impl<T> Receiver<T> {
                 }
             }
             ReceiverFlavor::Tick(chan) => {
-                let msg = chan.recv(Some(deadline));
+                let msg = chan.recv(deadline);
                 unsafe {
                     mem::transmute_copy::<
                         Result<Instant, RecvTimeoutError>,
@@ -806,7 +945,7 @@ impl<T> Receiver<T> {
                     >(&msg)
                 }
             }
-            ReceiverFlavor::Never(chan) => chan.recv(Some(deadline)),
+            ReceiverFlavor::Never(chan) => chan.recv(deadline),
         }
     }
 
@@ -1065,7 +1204,20 @@ impl<T> Clone for Receiver<T> {
 
 impl<T> fmt::Debug for Receiver<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.pad("Receiver { .. }")
+        let is_disconnected = match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.is_disconnected(),
+            ReceiverFlavor::List(chan) => chan.is_disconnected(),
+            ReceiverFlavor::Zero(chan) => chan.is_disconnected(),
+            ReceiverFlavor::After(_) => false,
+            ReceiverFlavor::Tick(_) => false,
+            ReceiverFlavor::Never(_) => false,
+        };
+
+        f.debug_struct("Receiver")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity())
+            .field("is_disconnected", &is_disconnected)
+            .finish()
     }
 }
 
@@ -338,6 +338,11 @@ impl<T> Channel<T> {
         }
     }
 
+    /// Returns `true` if the channel is disconnected.
+    pub fn is_disconnected(&self) -> bool {
+        self.inner.lock().is_disconnected
+    }
+
     /// Returns the current number of messages inside the channel.
     pub fn len(&self) -> usize {
         0
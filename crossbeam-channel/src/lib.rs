@@ -294,7 +294,9 @@
 //! ```
 //!
 //! If you need to select over a dynamically created list of channel operations, use [`Select`]
-//! instead. The [`select!`] macro is just a convenience wrapper around [`Select`].
+//! instead. The [`select!`] macro is just a convenience wrapper around [`Select`]. Unlike the
+//! macro, [`Select`] can also be configured with a [`SelectPolicy`] to prefer operations in the
+//! order they were added, or to let ready operations take turns.
 //!
 //! # Extra channels
 //!
@@ -341,6 +343,7 @@
 //! [`try_iter`]: struct.Receiver.html#method.try_iter
 //! [`select!`]: macro.select.html
 //! [`Select`]: struct.Select.html
+//! [`SelectPolicy`]: enum.SelectPolicy.html
 //! [`Sender`]: struct.Sender.html
 //! [`Receiver`]: struct.Receiver.html
 
@@ -371,7 +374,7 @@ pub use channel::{bounded, unbounded};
 pub use channel::{IntoIter, Iter, TryIter};
 pub use channel::{Receiver, Sender};
 
-pub use select::{Select, SelectedOperation};
+pub use select::{Select, SelectPolicy, SelectedOperation};
 
 pub use err::{ReadyTimeoutError, SelectTimeoutError, TryReadyError, TrySelectError};
 pub use err::{RecvError, RecvTimeoutError, TryRecvError};
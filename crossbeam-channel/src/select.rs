@@ -173,6 +173,7 @@ enum Timeout {
 fn run_select(
     handles: &mut [(&SelectHandle, usize, *const u8)],
     timeout: Timeout,
+    in_order: bool,
 ) -> Option<(Token, usize, *const u8)> {
     if handles.is_empty() {
         // Wait until the timeout and return.
@@ -189,8 +190,10 @@ fn run_select(
         }
     }
 
-    // Shuffle the operations for fairness.
-    utils::shuffle(handles);
+    // Shuffle the operations for fairness, unless they are to be tried in the given order.
+    if !in_order {
+        utils::shuffle(handles);
+    }
 
     // Create a token, which serves as a temporary variable that gets initialized in this function
     // and is later used by a call to `channel::read()` or `channel::write()` that completes the
@@ -317,7 +320,11 @@ fn run_select(
 }
 
 /// Runs until one of the operations becomes ready, potentially blocking the current thread.
-fn run_ready(handles: &mut [(&SelectHandle, usize, *const u8)], timeout: Timeout) -> Option<usize> {
+fn run_ready(
+    handles: &mut [(&SelectHandle, usize, *const u8)],
+    timeout: Timeout,
+    in_order: bool,
+) -> Option<usize> {
     if handles.is_empty() {
         // Wait until the timeout and return.
         match timeout {
@@ -333,8 +340,10 @@ fn run_ready(handles: &mut [(&SelectHandle, usize, *const u8)], timeout: Timeout
         }
     }
 
-    // Shuffle the operations for fairness.
-    utils::shuffle(handles);
+    // Shuffle the operations for fairness, unless they are to be tried in the given order.
+    if !in_order {
+        utils::shuffle(handles);
+    }
 
     loop {
         let backoff = Backoff::new();
@@ -441,8 +450,9 @@ fn run_ready(handles: &mut [(&SelectHandle, usize, *const u8)], timeout: Timeout
 #[inline]
 pub fn try_select<'a>(
     handles: &mut [(&'a SelectHandle, usize, *const u8)],
+    in_order: bool,
 ) -> Result<SelectedOperation<'a>, TrySelectError> {
-    match run_select(handles, Timeout::Now) {
+    match run_select(handles, Timeout::Now, in_order) {
         None => Err(TrySelectError),
         Some((token, index, ptr)) => Ok(SelectedOperation {
             token,
@@ -455,12 +465,15 @@ pub fn try_select<'a>(
 
 /// Blocks until one of the operations becomes ready and selects it.
 #[inline]
-pub fn select<'a>(handles: &mut [(&'a SelectHandle, usize, *const u8)]) -> SelectedOperation<'a> {
+pub fn select<'a>(
+    handles: &mut [(&'a SelectHandle, usize, *const u8)],
+    in_order: bool,
+) -> SelectedOperation<'a> {
     if handles.is_empty() {
         panic!("no operations have been added to `Select`");
     }
 
-    let (token, index, ptr) = run_select(handles, Timeout::Never).unwrap();
+    let (token, index, ptr) = run_select(handles, Timeout::Never, in_order).unwrap();
     SelectedOperation {
         token,
         index,
@@ -474,10 +487,14 @@ pub fn select<'a>(handles: &mut [(&'a SelectHandle, usize, *const u8)]) -> Selec
 pub fn select_timeout<'a>(
     handles: &mut [(&'a SelectHandle, usize, *const u8)],
     timeout: Duration,
+    in_order: bool,
 ) -> Result<SelectedOperation<'a>, SelectTimeoutError> {
-    let timeout = Timeout::At(Instant::now() + timeout);
+    let timeout = match utils::convert_timeout_to_deadline(timeout) {
+        Some(deadline) => Timeout::At(deadline),
+        None => Timeout::Never,
+    };
 
-    match run_select(handles, timeout) {
+    match run_select(handles, timeout, in_order) {
         None => Err(SelectTimeoutError),
         Some((token, index, ptr)) => Ok(SelectedOperation {
             token,
@@ -488,11 +505,35 @@ pub fn select_timeout<'a>(
     }
 }
 
+/// Decides which operation [`Select`] picks when multiple operations are ready at the same time.
+///
+/// A newly created `Select` uses the `Random` policy.
+///
+/// [`Select`]: struct.Select.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectPolicy {
+    /// A random ready operation is picked.
+    ///
+    /// Over time every ready operation gets an equal chance of being picked.
+    Random,
+
+    /// The ready operation that was added first is picked.
+    ///
+    /// Operations added later may starve if earlier ones are always ready.
+    Biased,
+
+    /// The first ready operation after the one picked last time is picked, wrapping around to the
+    /// beginning of the list.
+    ///
+    /// Ready operations take turns in the order in which they were added.
+    RoundRobin,
+}
+
 /// Selects from a set of channel operations.
 ///
 /// `Select` allows you to define a set of channel operations, wait until any one of them becomes
-/// ready, and finally execute it. If multiple operations are ready at the same time, a random one
-/// among them is selected.
+/// ready, and finally execute it. If multiple operations are ready at the same time, one of them
+/// is picked according to the [`SelectPolicy`], which by default picks a random one.
 ///
 /// An operation is considered to be ready if it doesn't have to block. Note that it is ready even
 /// when it will simply return an error because the channel is disconnected.
@@ -565,6 +606,7 @@ pub fn select_timeout<'a>(
 /// ```
 ///
 /// [`select!`]: macro.select.html
+/// [`SelectPolicy`]: enum.SelectPolicy.html
 /// [`try_select`]: struct.Select.html#method.try_select
 /// [`select`]: struct.Select.html#method.select
 /// [`select_timeout`]: struct.Select.html#method.select_timeout
@@ -574,6 +616,12 @@ pub fn select_timeout<'a>(
 pub struct Select<'a> {
     /// A list of senders and receivers participating in selection.
     handles: Vec<(&'a SelectHandle, usize, *const u8)>,
+
+    /// Decides which operation is picked when multiple operations are ready.
+    policy: SelectPolicy,
+
+    /// Index of the operation to try first under the round-robin policy.
+    next: usize,
 }
 
 unsafe impl<'a> Send for Select<'a> {}
@@ -595,9 +643,61 @@ impl<'a> Select<'a> {
     pub fn new() -> Select<'a> {
         Select {
             handles: Vec::with_capacity(4),
+            policy: SelectPolicy::Random,
+            next: 0,
         }
     }
 
+    /// Sets the policy that decides which operation is picked when multiple operations are ready
+    /// at the same time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_channel::{unbounded, Select, SelectPolicy};
+    ///
+    /// let (s1, r1) = unbounded();
+    /// let (s2, r2) = unbounded();
+    ///
+    /// s1.send(10).unwrap();
+    /// s2.send(20).unwrap();
+    ///
+    /// let mut sel = Select::new();
+    /// sel.set_policy(SelectPolicy::Biased);
+    /// let oper1 = sel.recv(&r1);
+    /// let oper2 = sel.recv(&r2);
+    ///
+    /// // Both operations are ready, so the one that was added first will be executed.
+    /// let oper = sel.select();
+    /// assert_eq!(oper.index(), oper1);
+    /// assert_eq!(oper.recv(&r1), Ok(10));
+    /// ```
+    pub fn set_policy(&mut self, policy: SelectPolicy) {
+        self.policy = policy;
+    }
+
+    /// Orders the operations for the next selection and returns `true` if they should be tried in
+    /// that order rather than shuffled.
+    fn prepare(&mut self) -> bool {
+        if self.policy == SelectPolicy::Random {
+            return false;
+        }
+
+        // A previous selection might have shuffled the operations, so restore the original order.
+        self.handles.sort_unstable_by_key(|&(_, i, _)| i);
+
+        if self.policy == SelectPolicy::RoundRobin && !self.handles.is_empty() {
+            let len = self.handles.len();
+            self.handles.rotate_left(self.next % len);
+        }
+        true
+    }
+
+    /// Remembers which operation was picked so that the next one gets priority next time.
+    fn picked(&mut self, index: usize) {
+        self.next = index + 1;
+    }
+
     /// Adds a send operation.
     ///
     /// Returns the index of the added operation.
@@ -645,8 +745,8 @@ impl<'a> Select<'a> {
     /// Attempts to select one of the operations without blocking.
     ///
     /// If an operation is ready, it is selected and returned. If multiple operations are ready at
-    /// the same time, a random one among them is selected. If none of the operations are ready, an
-    /// error is returned.
+    /// the same time, one of them is picked according to the [policy]. If none of the operations
+    /// are ready, an error is returned.
     ///
     /// An operation is considered to be ready if it doesn't have to block. Note that it is ready
     /// even when it will simply return an error because the channel is disconnected.
@@ -684,14 +784,22 @@ impl<'a> Select<'a> {
     ///     }
     /// }
     /// ```
+    ///
+    /// [policy]: struct.Select.html#method.set_policy
     pub fn try_select(&mut self) -> Result<SelectedOperation<'a>, TrySelectError> {
-        try_select(&mut self.handles)
+        let in_order = self.prepare();
+        let res = try_select(&mut self.handles, in_order);
+
+        if let Ok(oper) = &res {
+            self.picked(oper.index());
+        }
+        res
     }
 
     /// Blocks until one of the operations becomes ready and selects it.
     ///
     /// Once an operation becomes ready, it is selected and returned. If multiple operations are
-    /// ready at the same time, a random one among them is selected.
+    /// ready at the same time, one of them is picked according to the [policy].
     ///
     /// An operation is considered to be ready if it doesn't have to block. Note that it is ready
     /// even when it will simply return an error because the channel is disconnected.
@@ -734,15 +842,20 @@ impl<'a> Select<'a> {
     ///     _ => unreachable!(),
     /// }
     /// ```
+    ///
+    /// [policy]: struct.Select.html#method.set_policy
     pub fn select(&mut self) -> SelectedOperation<'a> {
-        select(&mut self.handles)
+        let in_order = self.prepare();
+        let oper = select(&mut self.handles, in_order);
+        self.picked(oper.index());
+        oper
     }
 
     /// Blocks for a limited time until one of the operations becomes ready and selects it.
     ///
     /// If an operation becomes ready, it is selected and returned. If multiple operations are
-    /// ready at the same time, a random one among them is selected. If none of the operations
-    /// become ready for the specified duration, an error is returned.
+    /// ready at the same time, one of them is picked according to the [policy]. If none of the
+    /// operations become ready for the specified duration, an error is returned.
     ///
     /// An operation is considered to be ready if it doesn't have to block. Note that it is ready
     /// even when it will simply return an error because the channel is disconnected.
@@ -784,18 +897,26 @@ impl<'a> Select<'a> {
     ///     }
     /// }
     /// ```
+    ///
+    /// [policy]: struct.Select.html#method.set_policy
     pub fn select_timeout(
         &mut self,
         timeout: Duration,
     ) -> Result<SelectedOperation<'a>, SelectTimeoutError> {
-        select_timeout(&mut self.handles, timeout)
+        let in_order = self.prepare();
+        let res = select_timeout(&mut self.handles, timeout, in_order);
+
+        if let Ok(oper) = &res {
+            self.picked(oper.index());
+        }
+        res
     }
 
     /// Attempts to find a ready operation without blocking.
     ///
     /// If an operation is ready, its index is returned. If multiple operations are ready at the
-    /// same time, a random one among them is chosen. If none of the operations are ready, an error
-    /// is returned.
+    /// same time, one of them is picked according to the [policy]. If none of the operations are
+    /// ready, an error is returned.
     ///
     /// An operation is considered to be ready if it doesn't have to block. Note that it is ready
     /// even when it will simply return an error because the channel is disconnected.
@@ -827,17 +948,24 @@ impl<'a> Select<'a> {
     ///     Ok(_) => unreachable!(),
     /// }
     /// ```
+    ///
+    /// [policy]: struct.Select.html#method.set_policy
     pub fn try_ready(&mut self) -> Result<usize, TryReadyError> {
-        match run_ready(&mut self.handles, Timeout::Now) {
+        let in_order = self.prepare();
+
+        match run_ready(&mut self.handles, Timeout::Now, in_order) {
             None => Err(TryReadyError),
-            Some(index) => Ok(index),
+            Some(index) => {
+                self.picked(index);
+                Ok(index)
+            }
         }
     }
 
     /// Blocks until one of the operations becomes ready.
     ///
     /// Once an operation becomes ready, its index is returned. If multiple operations are ready at
-    /// the same time, a random one among them is chosen.
+    /// the same time, one of them is picked according to the [policy].
     ///
     /// An operation is considered to be ready if it doesn't have to block. Note that it is ready
     /// even when it will simply return an error because the channel is disconnected.
@@ -876,19 +1004,24 @@ impl<'a> Select<'a> {
     ///     _ => unreachable!(),
     /// }
     /// ```
+    ///
+    /// [policy]: struct.Select.html#method.set_policy
     pub fn ready(&mut self) -> usize {
         if self.handles.is_empty() {
             panic!("no operations have been added to `Select`");
         }
 
-        run_ready(&mut self.handles, Timeout::Never).unwrap()
+        let in_order = self.prepare();
+        let index = run_ready(&mut self.handles, Timeout::Never, in_order).unwrap();
+        self.picked(index);
+        index
     }
 
     /// Blocks for a limited time until one of the operations becomes ready.
     ///
     /// If an operation becomes ready, its index is returned. If multiple operations are ready at
-    /// the same time, a random one among them is chosen. If none of the operations become ready
-    /// for the specified duration, an error is returned.
+    /// the same time, one of them is picked according to the [policy]. If none of the operations
+    /// become ready for the specified duration, an error is returned.
     ///
     /// An operation is considered to be ready if it doesn't have to block. Note that it is ready
     /// even when it will simply return an error because the channel is disconnected.
@@ -924,12 +1057,22 @@ impl<'a> Select<'a> {
     ///     Ok(_) => unreachable!(),
     /// }
     /// ```
+    ///
+    /// [policy]: struct.Select.html#method.set_policy
     pub fn ready_timeout(&mut self, timeout: Duration) -> Result<usize, ReadyTimeoutError> {
-        let timeout = Timeout::At(Instant::now() + timeout);
+        let timeout = match utils::convert_timeout_to_deadline(timeout) {
+            Some(deadline) => Timeout::At(deadline),
+            None => Timeout::Never,
+        };
+
+        let in_order = self.prepare();
 
-        match run_ready(&mut self.handles, timeout) {
+        match run_ready(&mut self.handles, timeout, in_order) {
             None => Err(ReadyTimeoutError),
-            Some(index) => Ok(index),
+            Some(index) => {
+                self.picked(index);
+                Ok(index)
+            }
         }
     }
 }
@@ -938,6 +1081,8 @@ impl<'a> Clone for Select<'a> {
     fn clone(&self) -> Select<'a> {
         Select {
             handles: self.handles.clone(),
+            policy: self.policy,
+            next: self.next,
         }
     }
 }
@@ -826,7 +826,7 @@ macro_rules! crossbeam_channel_internal {
         $cases:tt
     ) => {{
         let _oper: $crate::SelectedOperation<'_> = {
-            let _oper = $crate::internal::select(&mut $sel);
+            let _oper = $crate::internal::select(&mut $sel, false);
 
             // Erase the lifetime so that `sel` can be dropped early even without NLL.
             #[allow(unsafe_code)]
@@ -849,7 +849,7 @@ macro_rules! crossbeam_channel_internal {
         $cases:tt
     ) => {{
         let _oper: ::std::option::Option<$crate::SelectedOperation<'_>> = {
-            let _oper = $crate::internal::try_select(&mut $sel);
+            let _oper = $crate::internal::try_select(&mut $sel, false);
 
             // Erase the lifetime so that `sel` can be dropped early even without NLL.
             #[allow(unsafe_code)]
@@ -880,7 +880,7 @@ macro_rules! crossbeam_channel_internal {
         $cases:tt
     ) => {{
         let _oper: ::std::option::Option<$crate::SelectedOperation<'_>> = {
-            let _oper = $crate::internal::select_timeout(&mut $sel, $timeout);
+            let _oper = $crate::internal::select_timeout(&mut $sel, $timeout, false);
 
             // Erase the lifetime so that `sel` can be dropped early even without NLL.
             #[allow(unsafe_code)]
@@ -61,6 +61,22 @@ pub fn sleep_until(deadline: Option<Instant>) {
     }
 }
 
+/// Timeouts longer than this many seconds (roughly a century) never expire.
+const MAX_TIMEOUT_SECS: u64 = 100 * 365 * 24 * 60 * 60;
+
+/// Converts a timeout into a deadline, or `None` if the timeout never expires.
+///
+/// Adding a huge `Duration` such as `Duration::new(u64::MAX, 0)` to an `Instant` panics on
+/// overflow. Callers passing such a timeout mean "wait forever", so very long timeouts are
+/// treated as having no deadline at all.
+pub fn convert_timeout_to_deadline(timeout: Duration) -> Option<Instant> {
+    if timeout.as_secs() > MAX_TIMEOUT_SECS {
+        None
+    } else {
+        Some(Instant::now() + timeout)
+    }
+}
+
 /// A simple spinlock.
 pub struct Spinlock<T> {
     flag: AtomicBool,
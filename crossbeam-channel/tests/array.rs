@@ -315,6 +315,27 @@ fn len() {
     assert_eq!(r.len(), 0);
 }
 
+#[test]
+fn debug() {
+    let (s, r) = bounded::<()>(2);
+    s.send(()).unwrap();
+
+    assert_eq!(
+        format!("{:?}", s),
+        "Sender { len: 1, capacity: Some(2), is_disconnected: false }"
+    );
+    assert_eq!(
+        format!("{:?}", r),
+        "Receiver { len: 1, capacity: Some(2), is_disconnected: false }"
+    );
+
+    drop(s);
+    assert_eq!(
+        format!("{:?}", r),
+        "Receiver { len: 1, capacity: Some(2), is_disconnected: true }"
+    );
+}
+
 #[test]
 fn disconnect_wakes_sender() {
     let (s, r) = bounded(1);
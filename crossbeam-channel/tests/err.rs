@@ -0,0 +1,90 @@
+//! Tests for the error types.
+
+extern crate crossbeam_channel;
+
+use std::error::Error;
+
+use crossbeam_channel::{RecvError, RecvTimeoutError, TryRecvError};
+use crossbeam_channel::{SendError, SendTimeoutError, TrySendError};
+
+fn boxed<E: Error + Send + Sync + 'static>(e: E) -> Box<dyn Error + Send + Sync> {
+    Box::new(e)
+}
+
+#[test]
+fn into_boxed_error() {
+    // The message type implements neither `Debug` nor `Display`.
+    struct Message;
+
+    let errors = vec![
+        boxed(SendError(Message)),
+        boxed(TrySendError::Full(Message)),
+        boxed(TrySendError::Disconnected(Message)),
+        boxed(SendTimeoutError::Timeout(Message)),
+        boxed(SendTimeoutError::Disconnected(Message)),
+        boxed(RecvError),
+        boxed(TryRecvError::Empty),
+        boxed(TryRecvError::Disconnected),
+        boxed(RecvTimeoutError::Timeout),
+        boxed(RecvTimeoutError::Disconnected),
+    ];
+
+    for e in errors {
+        assert!(!e.to_string().is_empty());
+    }
+}
+
+#[test]
+fn question_mark() {
+    fn send() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (s, r) = crossbeam_channel::bounded(0);
+        drop(r);
+        s.send(1)?;
+        Ok(())
+    }
+
+    fn recv() -> Result<i32, Box<dyn Error + Send + Sync>> {
+        let (s, r) = crossbeam_channel::bounded(0);
+        drop(s);
+        Ok(r.recv()?)
+    }
+
+    assert_eq!(
+        send().unwrap_err().to_string(),
+        "sending on a disconnected channel"
+    );
+    assert_eq!(
+        recv().unwrap_err().to_string(),
+        "receiving on an empty and disconnected channel"
+    );
+}
+
+#[test]
+fn into_inner() {
+    assert_eq!(SendError(1).into_inner(), 1);
+    assert_eq!(TrySendError::Full(2).into_inner(), 2);
+    assert_eq!(TrySendError::Disconnected(3).into_inner(), 3);
+    assert_eq!(SendTimeoutError::Timeout(4).into_inner(), 4);
+    assert_eq!(SendTimeoutError::Disconnected(5).into_inner(), 5);
+}
+
+#[test]
+fn from_send_error() {
+    assert_eq!(
+        TrySendError::from(SendError(1)),
+        TrySendError::Disconnected(1)
+    );
+    assert_eq!(
+        SendTimeoutError::from(SendError(2)),
+        SendTimeoutError::Disconnected(2)
+    );
+}
+
+#[test]
+fn from_recv_error() {
+    assert_eq!(TryRecvError::from(RecvError), TryRecvError::Disconnected);
+    assert_eq!(
+        RecvTimeoutError::from(RecvError),
+        RecvTimeoutError::Disconnected
+    );
+}
@@ -226,6 +226,27 @@ fn len() {
     assert_eq!(r.len(), 0);
 }
 
+#[test]
+fn debug() {
+    let (s, r) = unbounded::<()>();
+    s.send(()).unwrap();
+
+    assert_eq!(
+        format!("{:?}", s),
+        "Sender { len: 1, capacity: None, is_disconnected: false }"
+    );
+    assert_eq!(
+        format!("{:?}", r),
+        "Receiver { len: 1, capacity: None, is_disconnected: false }"
+    );
+
+    drop(s);
+    assert_eq!(
+        format!("{:?}", r),
+        "Receiver { len: 1, capacity: None, is_disconnected: true }"
+    );
+}
+
 #[test]
 fn disconnect_wakes_receiver() {
     let (s, r) = unbounded::<()>();
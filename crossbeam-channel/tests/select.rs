@@ -7,14 +7,19 @@ use std::any::Any;
 use std::cell::Cell;
 use std::thread;
 use std::time::{Duration, Instant};
+use std::u64;
 
-use crossbeam_channel::{after, bounded, tick, unbounded, Receiver, Select, TryRecvError};
+use crossbeam_channel::TryRecvError;
+use crossbeam_channel::{after, bounded, tick, unbounded, Receiver, Select, SelectPolicy};
 use crossbeam_utils::thread::scope;
 
 fn ms(ms: u64) -> Duration {
     Duration::from_millis(ms)
 }
 
+/// The longest timeout that still gets a deadline instead of waiting forever.
+const MAX_TIMEOUT_SECS: u64 = 100 * 365 * 24 * 60 * 60;
+
 #[test]
 fn smoke1() {
     let (s1, r1) = unbounded::<usize>();
@@ -260,6 +265,47 @@ fn timeout() {
     .unwrap();
 }
 
+#[test]
+fn timeout_overflow() {
+    let (s, r) = unbounded::<i32>();
+
+    scope(|scope| {
+        scope.spawn(|_| {
+            for i in 0..4 {
+                thread::sleep(ms(500));
+                s.send(i).unwrap();
+            }
+        });
+
+        let timeouts = [
+            Duration::new(u64::MAX, 999_999_999),
+            Duration::from_secs(MAX_TIMEOUT_SECS),
+        ];
+
+        for (i, &timeout) in timeouts.iter().enumerate() {
+            let mut sel = Select::new();
+            let oper1 = sel.recv(&r);
+            let oper = sel.select_timeout(timeout);
+            match oper {
+                Err(_) => panic!(),
+                Ok(oper) => match oper.index() {
+                    ix if ix == oper1 => assert_eq!(oper.recv(&r), Ok(2 * i as i32)),
+                    _ => unreachable!(),
+                },
+            }
+
+            let mut sel = Select::new();
+            let oper1 = sel.recv(&r);
+            match sel.ready_timeout(timeout) {
+                Err(_) => panic!(),
+                Ok(ix) if ix == oper1 => assert_eq!(r.try_recv(), Ok(2 * i as i32 + 1)),
+                Ok(_) => unreachable!(),
+            }
+        }
+    })
+    .unwrap();
+}
+
 #[test]
 fn default_when_disconnected() {
     let (_, r) = unbounded::<i32>();
@@ -1210,6 +1256,78 @@ fn fairness2() {
     .unwrap();
 }
 
+#[test]
+fn policy_biased() {
+    const COUNT: usize = 1000;
+
+    let (s1, r1) = unbounded::<()>();
+    let (s2, r2) = unbounded::<()>();
+    let (s3, r3) = unbounded::<()>();
+
+    for _ in 0..COUNT {
+        s2.send(()).unwrap();
+        s3.send(()).unwrap();
+    }
+
+    let mut sel = Select::new();
+    sel.set_policy(SelectPolicy::Biased);
+    let oper1 = sel.recv(&r1);
+    let oper2 = sel.recv(&r2);
+    let oper3 = sel.recv(&r3);
+
+    // The first operation is never ready, and the second one always wins over the third one.
+    for _ in 0..COUNT {
+        let oper = sel.select();
+        assert_eq!(oper.index(), oper2);
+        oper.recv(&r2).unwrap();
+    }
+
+    // Now only the third operation is ready.
+    assert_eq!(sel.try_ready(), Ok(oper3));
+    drop(s1);
+    assert_eq!(sel.try_ready(), Ok(oper1));
+}
+
+#[test]
+fn policy_round_robin() {
+    const COUNT: usize = 1000;
+
+    let (s1, r1) = unbounded::<()>();
+    let (s2, r2) = unbounded::<()>();
+    let (_s3, r3) = unbounded::<()>();
+    let (s4, r4) = unbounded::<()>();
+
+    for _ in 0..COUNT {
+        s1.send(()).unwrap();
+        s2.send(()).unwrap();
+        s4.send(()).unwrap();
+    }
+
+    let rs = [&r1, &r2, &r3, &r4];
+    let mut sel = Select::new();
+    sel.set_policy(SelectPolicy::RoundRobin);
+    for r in &rs {
+        sel.recv(r);
+    }
+
+    // Ready operations take turns in order, and the third one is skipped because it's never ready.
+    for i in 0..COUNT {
+        let oper = sel.select();
+        assert_eq!(oper.index(), [0, 1, 3][i % 3]);
+        let index = oper.index();
+        oper.recv(rs[index]).unwrap();
+    }
+
+    // Waiting for readiness takes turns as well.
+    let mut sel = Select::new();
+    sel.set_policy(SelectPolicy::RoundRobin);
+    sel.recv(&r1);
+    sel.recv(&r2);
+    for i in 0..10 {
+        assert_eq!(sel.ready(), i % 2);
+    }
+}
+
 #[test]
 fn sync_and_clone() {
     const THREADS: usize = 20;
@@ -1302,3 +1420,55 @@ fn reuse() {
     })
     .unwrap();
 }
+
+#[test]
+fn dynamic_list() {
+    const THREADS: usize = 8;
+    const COUNT: usize = 100;
+
+    let mut senders = Vec::new();
+    let mut rs = Vec::new();
+    for _ in 0..THREADS {
+        let (s, r) = bounded::<usize>(0);
+        senders.push(s);
+        rs.push(r);
+    }
+
+    scope(|scope| {
+        for (i, s) in senders.into_iter().enumerate() {
+            scope.spawn(move |_| {
+                for j in 0..COUNT {
+                    s.send(i * COUNT + j).unwrap();
+                }
+            });
+        }
+
+        let mut hits = [0usize; THREADS];
+        let mut live = (0..THREADS).collect::<Vec<_>>();
+
+        while !live.is_empty() {
+            let mut sel = Select::new();
+            for &i in &live {
+                sel.recv(&rs[i]);
+            }
+
+            let oper = sel.select();
+            let index = oper.index();
+            let i = live[index];
+
+            match oper.recv(&rs[i]) {
+                Ok(msg) => {
+                    assert_eq!(msg / COUNT, i);
+                    assert_eq!(msg % COUNT, hits[i]);
+                    hits[i] += 1;
+                }
+                Err(_) => {
+                    live.remove(index);
+                }
+            }
+        }
+
+        assert!(hits.iter().all(|&x| x == COUNT));
+    })
+    .unwrap();
+}
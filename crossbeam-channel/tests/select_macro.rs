@@ -11,6 +11,7 @@ use std::cell::Cell;
 use std::ops::Deref;
 use std::thread;
 use std::time::{Duration, Instant};
+use std::u64;
 
 use crossbeam_channel::{after, bounded, never, tick, unbounded};
 use crossbeam_channel::{Receiver, RecvError, SendError, Sender, TryRecvError};
@@ -20,6 +21,9 @@ fn ms(ms: u64) -> Duration {
     Duration::from_millis(ms)
 }
 
+/// The longest timeout that still gets a deadline instead of waiting forever.
+const MAX_TIMEOUT_SECS: u64 = 100 * 365 * 24 * 60 * 60;
+
 #[test]
 fn smoke1() {
     let (s1, r1) = unbounded::<usize>();
@@ -182,6 +186,31 @@ fn timeout() {
     .unwrap();
 }
 
+#[test]
+fn timeout_overflow() {
+    let (s, r) = unbounded::<i32>();
+
+    scope(|scope| {
+        scope.spawn(|_| {
+            thread::sleep(ms(500));
+            s.send(1).unwrap();
+            thread::sleep(ms(500));
+            s.send(2).unwrap();
+        });
+
+        select! {
+            recv(r) -> v => assert_eq!(v, Ok(1)),
+            default(Duration::new(u64::MAX, 999_999_999)) => panic!(),
+        }
+
+        select! {
+            recv(r) -> v => assert_eq!(v, Ok(2)),
+            default(Duration::from_secs(MAX_TIMEOUT_SECS)) => panic!(),
+        }
+    })
+    .unwrap();
+}
+
 #[test]
 fn default_when_disconnected() {
     let (_, r) = unbounded::<i32>();
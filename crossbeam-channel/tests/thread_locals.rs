@@ -4,10 +4,11 @@
 extern crate crossbeam_channel;
 extern crate crossbeam_utils;
 
+use std::cell::RefCell;
 use std::thread;
 use std::time::Duration;
 
-use crossbeam_channel::unbounded;
+use crossbeam_channel::{bounded, unbounded, Sender};
 use crossbeam_utils::thread::scope;
 
 fn ms(ms: u64) -> Duration {
@@ -53,3 +54,42 @@ fn use_while_exiting() {
     })
     .unwrap();
 }
+
+#[test]
+#[cfg_attr(target_os = "macos", ignore = "TLS is destroyed too early on macOS")]
+fn block_while_exiting() {
+    struct Foo(Option<Sender<()>>);
+
+    impl Drop for Foo {
+        fn drop(&mut self) {
+            // A blocking send from a thread-local destructor. The thread has to register itself
+            // and park until the receiver shows up.
+            if let Some(s) = self.0.take() {
+                s.send(()).unwrap();
+            }
+        }
+    }
+
+    thread_local! {
+        static FOO: RefCell<Foo> = RefCell::new(Foo(None));
+    }
+
+    let (s, r) = bounded::<()>(0);
+
+    scope(|scope| {
+        scope.spawn(|_| {
+            // First initialize `FOO`, then the thread-locals related to crossbeam-channel.
+            FOO.with(|foo| foo.borrow_mut().0 = Some(s));
+            let (_s, r) = bounded::<()>(0);
+            assert!(r.recv_timeout(ms(1)).is_err());
+            // At thread exit, thread-locals related to crossbeam-channel get dropped first and
+            // `FOO` is dropped last.
+        });
+
+        scope.spawn(|_| {
+            thread::sleep(ms(100));
+            r.recv().unwrap();
+        });
+    })
+    .unwrap();
+}
@@ -9,9 +9,10 @@ use std::any::Any;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use std::u64;
 
-use crossbeam_channel::{bounded, Receiver};
+use crossbeam_channel::{bounded, Receiver, Sender};
 use crossbeam_channel::{RecvError, RecvTimeoutError, TryRecvError};
 use crossbeam_channel::{SendError, SendTimeoutError, TrySendError};
 use crossbeam_utils::thread::scope;
@@ -21,6 +22,9 @@ fn ms(ms: u64) -> Duration {
     Duration::from_millis(ms)
 }
 
+/// The longest timeout that still gets a deadline instead of waiting forever.
+const MAX_TIMEOUT_SECS: u64 = 100 * 365 * 24 * 60 * 60;
+
 #[test]
 fn smoke() {
     let (s, r) = bounded(0);
@@ -124,6 +128,55 @@ fn recv_timeout() {
     .unwrap();
 }
 
+#[test]
+fn recv_deadline() {
+    let (s, r) = bounded::<i32>(0);
+
+    scope(|scope| {
+        scope.spawn(move |_| {
+            let start = Instant::now();
+            assert_eq!(
+                r.recv_deadline(start + ms(1000)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(r.recv_deadline(start + ms(2000)), Ok(7));
+            assert_eq!(
+                r.recv_deadline(start + ms(3000)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+        });
+        scope.spawn(move |_| {
+            thread::sleep(ms(1500));
+            s.send(7).unwrap();
+        });
+    })
+    .unwrap();
+}
+
+#[test]
+fn recv_timeout_overflow() {
+    let (s, r) = bounded::<i32>(0);
+
+    scope(|scope| {
+        scope.spawn(move |_| {
+            assert_eq!(r.recv_timeout(Duration::new(u64::MAX, 999_999_999)), Ok(7));
+            assert_eq!(r.recv_timeout(Duration::from_secs(MAX_TIMEOUT_SECS)), Ok(8));
+            assert_eq!(
+                r.recv_timeout(Duration::from_secs(u64::MAX / 2)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+        });
+        scope.spawn(move |_| {
+            thread::sleep(ms(1000));
+            s.send(7).unwrap();
+            thread::sleep(ms(1000));
+            s.send(8).unwrap();
+            thread::sleep(ms(1000));
+        });
+    })
+    .unwrap();
+}
+
 #[test]
 fn try_send() {
     let (s, r) = bounded(0);
@@ -190,6 +243,83 @@ fn send_timeout() {
     .unwrap();
 }
 
+#[test]
+fn send_deadline() {
+    let (s, r) = bounded(0);
+
+    scope(|scope| {
+        scope.spawn(move |_| {
+            let start = Instant::now();
+            assert_eq!(
+                s.send_deadline(7, start + ms(1000)),
+                Err(SendTimeoutError::Timeout(7))
+            );
+            assert_eq!(s.send_deadline(8, start + ms(2000)), Ok(()));
+            assert_eq!(
+                s.send_deadline(9, start + ms(3000)),
+                Err(SendTimeoutError::Disconnected(9))
+            );
+        });
+        scope.spawn(move |_| {
+            thread::sleep(ms(1500));
+            assert_eq!(r.recv(), Ok(8));
+        });
+    })
+    .unwrap();
+}
+
+#[test]
+fn deadline_in_past() {
+    let (s, r) = bounded(0);
+    let past = Instant::now();
+    thread::sleep(ms(10));
+
+    let start = Instant::now();
+    assert_eq!(r.recv_deadline(past), Err(RecvTimeoutError::Timeout));
+    assert_eq!(s.send_deadline(1, past), Err(SendTimeoutError::Timeout(1)));
+    assert!(start.elapsed() < ms(500));
+
+    scope(|scope| {
+        scope.spawn(|_| {
+            thread::sleep(ms(100));
+            assert_eq!(r.recv(), Ok(2));
+        });
+
+        assert_eq!(s.send_deadline(2, Instant::now() + ms(1000)), Ok(()));
+    })
+    .unwrap();
+}
+
+#[test]
+fn send_timeout_overflow() {
+    let (s, r) = bounded(0);
+
+    scope(|scope| {
+        scope.spawn(move |_| {
+            assert_eq!(
+                s.send_timeout(7, Duration::new(u64::MAX, 999_999_999)),
+                Ok(())
+            );
+            assert_eq!(
+                s.send_timeout(8, Duration::from_secs(MAX_TIMEOUT_SECS)),
+                Ok(())
+            );
+            assert_eq!(
+                s.send_timeout(9, Duration::from_secs(u64::MAX / 2)),
+                Err(SendTimeoutError::Disconnected(9))
+            );
+        });
+        scope.spawn(move |_| {
+            thread::sleep(ms(1000));
+            assert_eq!(r.recv(), Ok(7));
+            thread::sleep(ms(1000));
+            assert_eq!(r.recv(), Ok(8));
+            thread::sleep(ms(1000));
+        });
+    })
+    .unwrap();
+}
+
 #[test]
 fn len() {
     const COUNT: usize = 25_000;
@@ -220,6 +350,38 @@ fn len() {
     assert_eq!(r.len(), 0);
 }
 
+#[test]
+fn debug() {
+    // The message type doesn't need to implement `Debug`.
+    struct Message;
+
+    #[derive(Debug)]
+    struct Handles {
+        s: Sender<Message>,
+        r: Receiver<Message>,
+    }
+
+    let (s, r) = bounded::<Message>(0);
+    let h = Handles { s, r };
+
+    assert_eq!(
+        format!("{:?}", h.s),
+        "Sender { len: 0, capacity: Some(0), is_disconnected: false }"
+    );
+    assert_eq!(
+        format!("{:?}", h.r),
+        "Receiver { len: 0, capacity: Some(0), is_disconnected: false }"
+    );
+    assert!(format!("{:?}", h).starts_with("Handles { s: Sender {"));
+
+    let Handles { s, r } = h;
+    drop(s);
+    assert_eq!(
+        format!("{:?}", r),
+        "Receiver { len: 0, capacity: Some(0), is_disconnected: true }"
+    );
+}
+
 #[test]
 fn disconnect_wakes_sender() {
     let (s, r) = bounded(0);
@@ -252,6 +414,171 @@ fn disconnect_wakes_receiver() {
     .unwrap();
 }
 
+#[test]
+fn concurrent_disconnect() {
+    const THREADS: usize = 10;
+    const ROUNDS: usize = 20;
+
+    for _ in 0..ROUNDS {
+        // The last senders are dropped at the same time by many threads.
+        let (s, r) = bounded::<usize>(0);
+        let received = AtomicUsize::new(0);
+
+        scope(|scope| {
+            for _ in 0..THREADS {
+                let r = r.clone();
+                let received = &received;
+                scope.spawn(move |_| {
+                    while r.recv().is_ok() {
+                        received.fetch_add(1, Ordering::SeqCst);
+                    }
+                });
+            }
+            for i in 0..THREADS {
+                let s = s.clone();
+                scope.spawn(move |_| {
+                    s.send(i).unwrap();
+                });
+            }
+            drop(s);
+            drop(r);
+        })
+        .unwrap();
+
+        assert_eq!(received.load(Ordering::SeqCst), THREADS);
+
+        // The last receivers are dropped at the same time by many threads.
+        let (s, r) = bounded::<usize>(0);
+
+        scope(|scope| {
+            for i in 0..THREADS {
+                let s = s.clone();
+                scope.spawn(move |_| {
+                    assert_eq!(s.send(i), Err(SendError(i)));
+                });
+            }
+            for _ in 0..THREADS {
+                let r = r.clone();
+                scope.spawn(move |_| {
+                    thread::sleep(ms(10));
+                    drop(r);
+                });
+            }
+            drop(s);
+            drop(r);
+        })
+        .unwrap();
+    }
+}
+
+#[test]
+fn unpark_while_blocked() {
+    let (s, r) = bounded::<i32>(0);
+
+    scope(|scope| {
+        let h = scope.spawn(move |_| {
+            // Leave a stray unpark token behind before blocking.
+            thread::current().unpark();
+            assert_eq!(r.recv(), Ok(7));
+
+            thread::current().unpark();
+            assert_eq!(r.recv_timeout(ms(200)), Err(RecvTimeoutError::Timeout));
+        });
+
+        // Unparking the thread from the outside must not make the blocked operation return early.
+        for _ in 0..10 {
+            thread::sleep(ms(20));
+            h.thread().unpark();
+        }
+        s.send(7).unwrap();
+    })
+    .unwrap();
+}
+
+#[test]
+fn fifo_senders() {
+    const THREADS: usize = 8;
+
+    let (s, r) = bounded::<usize>(0);
+
+    scope(|scope| {
+        for i in 0..THREADS {
+            let s = s.clone();
+            scope.spawn(move |_| {
+                if i % 3 == 1 {
+                    // This sender leaves the queue of waiting senders before a receiver arrives.
+                    assert_eq!(
+                        s.send_timeout(i, ms(500)),
+                        Err(SendTimeoutError::Timeout(i))
+                    );
+                } else {
+                    s.send(i).unwrap();
+                }
+            });
+
+            // Give the thread time to block before spawning the next one.
+            thread::sleep(ms(50));
+        }
+
+        thread::sleep(ms(1000));
+
+        // Blocked senders are paired with receivers in the order in which they arrived.
+        for i in (0..THREADS).filter(|i| i % 3 != 1) {
+            assert_eq!(r.recv(), Ok(i));
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn try_iter() {
+    const THREADS: usize = 4;
+
+    let (s, r) = bounded::<usize>(0);
+
+    scope(|scope| {
+        for i in 0..THREADS {
+            let s = s.clone();
+            scope.spawn(move |_| {
+                s.send(i).unwrap();
+            });
+        }
+
+        // Wait until all senders are blocked.
+        thread::sleep(ms(1000));
+
+        let mut v = r.try_iter().collect::<Vec<_>>();
+        v.sort();
+        assert_eq!(v, (0..THREADS).collect::<Vec<_>>());
+
+        // No more senders are waiting.
+        assert_eq!(r.try_iter().next(), None);
+    })
+    .unwrap();
+}
+
+#[test]
+fn send_iter() {
+    const COUNT: usize = 100;
+
+    let (s, r) = bounded(0);
+
+    scope(|scope| {
+        scope.spawn(move |_| {
+            for i in 0..COUNT {
+                assert_eq!(r.recv(), Ok(i));
+            }
+        });
+
+        assert!(s.send_iter(0..COUNT).is_ok());
+
+        let (msg, rest) = s.send_iter(COUNT..COUNT + 3).unwrap_err().into_inner();
+        assert_eq!(msg, COUNT);
+        assert_eq!(rest.collect::<Vec<_>>(), [COUNT + 1, COUNT + 2]);
+    })
+    .unwrap();
+}
+
 #[test]
 fn spsc() {
     const COUNT: usize = 100_000;
@@ -431,6 +758,154 @@ fn drops() {
     }
 }
 
+#[test]
+fn drops_on_timeout_race() {
+    const COUNT: usize = 1_000;
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, PartialEq)]
+    struct DropCounter(usize);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let (s, r) = bounded::<DropCounter>(0);
+    let sent = AtomicUsize::new(0);
+    let received = AtomicUsize::new(0);
+
+    scope(|scope| {
+        scope.spawn(|_| {
+            for i in 0..COUNT {
+                // Time out at roughly the same moment the receiver shows up.
+                match s.send_timeout(DropCounter(i), Duration::from_micros(50)) {
+                    Ok(()) => {
+                        sent.fetch_add(1, Ordering::SeqCst);
+                    }
+                    Err(SendTimeoutError::Timeout(msg)) => assert_eq!(msg.0, i),
+                    Err(SendTimeoutError::Disconnected(_)) => unreachable!(),
+                }
+            }
+        });
+
+        scope.spawn(|_| {
+            let mut rng = thread_rng();
+            for _ in 0..COUNT {
+                thread::sleep(Duration::from_micros(rng.gen_range(0, 100)));
+                if let Ok(msg) = r.recv_timeout(Duration::from_micros(50)) {
+                    assert!(msg.0 < COUNT);
+                    received.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+        });
+    })
+    .unwrap();
+
+    // Every message was either handed off exactly once or given back to the sender, and each
+    // one was dropped exactly once.
+    assert_eq!(sent.load(Ordering::SeqCst), received.load(Ordering::SeqCst));
+    assert_eq!(DROPS.load(Ordering::SeqCst), COUNT);
+}
+
+#[test]
+fn recv_timeout_disconnect_race() {
+    const COUNT: usize = 1_000;
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct DropCounter;
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let sent = AtomicUsize::new(0);
+    let received = AtomicUsize::new(0);
+
+    for _ in 0..COUNT {
+        let (s, r) = bounded::<DropCounter>(0);
+
+        scope(|scope| {
+            // The sender hands off a message and then disconnects the channel, racing with the
+            // receiver timing out and unregistering itself.
+            scope.spawn(|_| {
+                let mut rng = thread_rng();
+                thread::sleep(Duration::from_micros(rng.gen_range(0, 2_000)));
+                if s.send_timeout(DropCounter, Duration::from_micros(50))
+                    .is_ok()
+                {
+                    sent.fetch_add(1, Ordering::SeqCst);
+                }
+                drop(s);
+            });
+
+            if r.recv_timeout(ms(1)).is_ok() {
+                received.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .unwrap();
+    }
+
+    // A message delivered to the receiver is never lost, even if it timed out or the channel got
+    // disconnected at the same time.
+    assert_eq!(sent.load(Ordering::SeqCst), received.load(Ordering::SeqCst));
+    assert_eq!(DROPS.load(Ordering::SeqCst), COUNT);
+}
+
+#[test]
+fn send_timeout_many_receivers() {
+    const COUNT: usize = 1_000;
+    const THREADS: usize = 4;
+
+    let (s, r) = bounded::<usize>(0);
+    let returned = (0..COUNT).map(|_| AtomicUsize::new(0)).collect::<Vec<_>>();
+    let received = (0..COUNT).map(|_| AtomicUsize::new(0)).collect::<Vec<_>>();
+
+    scope(|scope| {
+        let returned = &returned;
+        scope.spawn(move |_| {
+            for i in 0..COUNT {
+                match s.send_timeout(i, Duration::from_micros(100)) {
+                    Ok(()) => {}
+                    Err(SendTimeoutError::Timeout(msg)) => {
+                        returned[msg].fetch_add(1, Ordering::SeqCst);
+                    }
+                    Err(SendTimeoutError::Disconnected(_)) => unreachable!(),
+                }
+            }
+        });
+
+        for _ in 0..THREADS {
+            scope.spawn(|_| {
+                let mut rng = thread_rng();
+                loop {
+                    thread::sleep(Duration::from_micros(rng.gen_range(0, 1_000)));
+                    match r.recv_timeout(Duration::from_micros(50)) {
+                        Ok(msg) => {
+                            received[msg].fetch_add(1, Ordering::SeqCst);
+                        }
+                        Err(RecvTimeoutError::Timeout) => {}
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    }
+                }
+            });
+        }
+    })
+    .unwrap();
+
+    // Every message was either received exactly once or given back to the sender exactly once.
+    for i in 0..COUNT {
+        let returned = returned[i].load(Ordering::SeqCst);
+        let received = received[i].load(Ordering::SeqCst);
+        assert_eq!(returned + received, 1);
+    }
+}
+
 #[test]
 fn fairness() {
     const COUNT: usize = 10_000;
@@ -7,6 +7,7 @@
 //!
 //! ## Thread synchronization
 //!
+//! * [`Notify`], for waking up threads blocked waiting for a notification.
 //! * [`Parker`], a thread parking primitive.
 //! * [`ShardedLock`], a sharded reader-writer lock with fast concurrent reads.
 //! * [`WaitGroup`], for synchronizing the beginning or end of some computation.
@@ -19,6 +20,7 @@
 //!
 //! [`AtomicCell`]: atomic/struct.AtomicCell.html
 //! [`AtomicConsume`]: atomic/trait.AtomicConsume.html
+//! [`Notify`]: sync/struct.Notify.html
 //! [`Parker`]: sync/struct.Parker.html
 //! [`ShardedLock`]: sync/struct.ShardedLock.html
 //! [`WaitGroup`]: sync/struct.WaitGroup.html
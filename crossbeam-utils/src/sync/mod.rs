@@ -1,17 +1,21 @@
 //! Thread synchronization primitives.
 //!
+//! * [`Notify`], for waking up threads blocked waiting for a notification.
 //! * [`Parker`], a thread parking primitive.
 //! * [`ShardedLock`], a sharded reader-writer lock with fast concurrent reads.
 //! * [`WaitGroup`], for synchronizing the beginning or end of some computation.
 //!
+//! [`Notify`]: struct.Notify.html
 //! [`Parker`]: struct.Parker.html
 //! [`ShardedLock`]: struct.ShardedLock.html
 //! [`WaitGroup`]: struct.WaitGroup.html
 
+mod notify;
 mod parker;
 mod sharded_lock;
 mod wait_group;
 
 pub use self::sharded_lock::{ShardedLock, ShardedLockReadGuard, ShardedLockWriteGuard};
+pub use self::notify::Notify;
 pub use self::parker::{Parker, Unparker};
 pub use self::wait_group::WaitGroup;
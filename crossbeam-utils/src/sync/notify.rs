@@ -0,0 +1,255 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+/// Wakes up threads blocked waiting for a notification.
+///
+/// Threads block by calling [`wait`] or [`wait_timeout`] and get woken up by [`notify_one`] or
+/// [`notify_all`]. Blocked threads are woken up in the order in which they started waiting.
+///
+/// # Notifications are not stored
+///
+/// A notification only wakes up threads that are blocked at the moment it is sent. If no thread
+/// is waiting, [`notify_one`] does nothing and a later call to [`wait`] will block until the next
+/// notification.
+///
+/// This is what sets `Notify` apart from a counting semaphore or a [`Parker`], where a wakeup
+/// that happens before the thread blocks is remembered and consumed by the next wait.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use crossbeam_utils::sync::Notify;
+///
+/// let notify = Arc::new(Notify::new());
+///
+/// let handle = thread::spawn({
+///     let notify = notify.clone();
+///     move || {
+///         // Block until notified.
+///         notify.wait();
+///     }
+/// });
+///
+/// // Notifications are lost if nobody is waiting, so keep trying until the spawned thread has
+/// // actually been woken up.
+/// while !notify.notify_one() {
+///     thread::yield_now();
+/// }
+///
+/// handle.join().unwrap();
+/// ```
+///
+/// [`wait`]: struct.Notify.html#method.wait
+/// [`wait_timeout`]: struct.Notify.html#method.wait_timeout
+/// [`notify_one`]: struct.Notify.html#method.notify_one
+/// [`notify_all`]: struct.Notify.html#method.notify_all
+/// [`Parker`]: struct.Parker.html
+pub struct Notify {
+    /// Threads waiting for a notification, in the order they started waiting.
+    waiters: Mutex<VecDeque<Arc<Waiter>>>,
+}
+
+/// A thread blocked on a `Notify`.
+struct Waiter {
+    /// The blocked thread.
+    thread: Thread,
+
+    /// Set to `true` once the thread has been notified.
+    notified: AtomicBool,
+}
+
+impl Notify {
+    /// Creates a new `Notify` with no waiting threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_utils::sync::Notify;
+    ///
+    /// let notify = Notify::new();
+    /// ```
+    pub fn new() -> Notify {
+        Notify {
+            waiters: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Blocks the current thread until it is notified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::thread;
+    /// use crossbeam_utils::sync::Notify;
+    ///
+    /// let notify = Arc::new(Notify::new());
+    /// let n = notify.clone();
+    ///
+    /// thread::spawn(move || {
+    ///     while !n.notify_one() {
+    ///         thread::yield_now();
+    ///     }
+    /// });
+    ///
+    /// // Blocks until the spawned thread sends a notification.
+    /// notify.wait();
+    /// ```
+    pub fn wait(&self) {
+        self.wait_until(None);
+    }
+
+    /// Blocks the current thread until it is notified, but only for a limited time.
+    ///
+    /// Returns `true` if the thread was notified and `false` if the operation timed out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use crossbeam_utils::sync::Notify;
+    ///
+    /// let notify = Notify::new();
+    ///
+    /// // This notification is lost because nobody is waiting.
+    /// notify.notify_one();
+    ///
+    /// // Times out because notifications are not stored.
+    /// assert!(!notify.wait_timeout(Duration::from_millis(100)));
+    /// ```
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        self.wait_until(deadline(timeout))
+    }
+
+    /// Wakes up the thread that has been waiting the longest.
+    ///
+    /// Returns `true` if a thread was woken up. If no thread is waiting, the notification is lost
+    /// and `false` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_utils::sync::Notify;
+    ///
+    /// let notify = Notify::new();
+    ///
+    /// // Nobody is waiting, so this does nothing.
+    /// assert!(!notify.notify_one());
+    /// ```
+    pub fn notify_one(&self) -> bool {
+        let mut waiters = self.waiters.lock().unwrap();
+
+        match waiters.pop_front() {
+            None => false,
+            Some(waiter) => {
+                waiter.notify();
+                true
+            }
+        }
+    }
+
+    /// Wakes up all waiting threads.
+    ///
+    /// Returns the number of threads that were woken up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_utils::sync::Notify;
+    ///
+    /// let notify = Notify::new();
+    ///
+    /// // Nobody is waiting, so this does nothing.
+    /// assert_eq!(notify.notify_all(), 0);
+    /// ```
+    pub fn notify_all(&self) -> usize {
+        let mut waiters = self.waiters.lock().unwrap();
+        let count = waiters.len();
+
+        for waiter in waiters.drain(..) {
+            waiter.notify();
+        }
+        count
+    }
+
+    /// Blocks the current thread until it is notified or the deadline is reached.
+    ///
+    /// Returns `true` if the thread was notified.
+    fn wait_until(&self, deadline: Option<Instant>) -> bool {
+        let waiter = Arc::new(Waiter {
+            thread: thread::current(),
+            notified: AtomicBool::new(false),
+        });
+        self.waiters.lock().unwrap().push_back(waiter.clone());
+
+        loop {
+            if waiter.notified.load(Ordering::Acquire) {
+                return true;
+            }
+
+            match deadline {
+                None => thread::park(),
+                Some(end) => {
+                    let now = Instant::now();
+
+                    if now < end {
+                        thread::park_timeout(end - now);
+                    } else {
+                        // Notifications are sent while holding the lock, so once we hold it the
+                        // outcome can't change anymore.
+                        let mut waiters = self.waiters.lock().unwrap();
+
+                        if waiter.notified.load(Ordering::Acquire) {
+                            return true;
+                        }
+
+                        waiters.retain(|w| !Arc::ptr_eq(w, &waiter));
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Waiter {
+    /// Marks the waiter as notified and wakes up its thread.
+    fn notify(&self) {
+        self.notified.store(true, Ordering::Release);
+        self.thread.unpark();
+    }
+}
+
+impl Default for Notify {
+    fn default() -> Notify {
+        Notify::new()
+    }
+}
+
+impl fmt::Debug for Notify {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let waiters = self.waiters.lock().unwrap().len();
+        f.debug_struct("Notify").field("waiters", &waiters).finish()
+    }
+}
+
+/// A `wait_timeout` longer than this many seconds waits for a notification without a deadline.
+const MAX_TIMEOUT_SECS: u64 = 100 * 365 * 24 * 60 * 60;
+
+/// Returns the deadline for `wait_timeout`, or `None` if the wait shouldn't time out.
+///
+/// `Instant::now() + timeout` panics on overflow, so a timeout too long to ever expire is not
+/// added to the current time at all.
+fn deadline(timeout: Duration) -> Option<Instant> {
+    if timeout.as_secs() > MAX_TIMEOUT_SECS {
+        None
+    } else {
+        Some(Instant::now() + timeout)
+    }
+}
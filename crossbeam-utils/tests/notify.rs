@@ -0,0 +1,147 @@
+extern crate crossbeam_utils;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread::sleep;
+use std::time::Duration;
+use std::u64;
+
+use crossbeam_utils::sync::Notify;
+use crossbeam_utils::thread;
+
+const THREADS: usize = 10;
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+#[test]
+fn notify_without_waiters() {
+    let notify = Notify::new();
+
+    assert!(!notify.notify_one());
+    assert_eq!(notify.notify_all(), 0);
+
+    // Neither notification was stored.
+    assert!(!notify.wait_timeout(ms(100)));
+}
+
+#[test]
+fn wait_timeout() {
+    let notify = Notify::new();
+
+    thread::scope(|scope| {
+        scope.spawn(|_| {
+            assert!(!notify.wait_timeout(ms(100)));
+            assert!(notify.wait_timeout(ms(1000)));
+        });
+
+        sleep(ms(500));
+        assert!(notify.notify_one());
+    })
+    .unwrap();
+}
+
+#[test]
+fn wait_timeout_overflow() {
+    let notify = Notify::new();
+
+    thread::scope(|scope| {
+        scope.spawn(|_| {
+            // A timeout this long never expires, so only the notification wakes the thread up.
+            assert!(notify.wait_timeout(Duration::new(u64::MAX, 0)));
+        });
+
+        sleep(ms(500));
+        assert!(notify.notify_one());
+    })
+    .unwrap();
+}
+
+#[test]
+fn notify_one() {
+    let notify = Notify::new();
+    let woken = AtomicUsize::new(0);
+
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            scope.spawn(|_| {
+                notify.wait();
+                woken.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        sleep(ms(100));
+
+        for i in 0..THREADS {
+            while !notify.notify_one() {
+                sleep(ms(10));
+            }
+
+            while woken.load(Ordering::SeqCst) == i {
+                sleep(ms(10));
+            }
+
+            // Only a single thread gets woken up by each notification.
+            sleep(ms(10));
+            assert_eq!(woken.load(Ordering::SeqCst), i + 1);
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn notify_all() {
+    let notify = Notify::new();
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            let tx = tx.clone();
+            let notify = &notify;
+
+            scope.spawn(move |_| {
+                notify.wait();
+                tx.send(()).unwrap();
+            });
+        }
+
+        sleep(ms(100));
+
+        // At this point, all spawned threads should be blocked.
+        assert!(rx.try_recv().is_err());
+        assert_eq!(notify.notify_all(), THREADS);
+
+        for _ in 0..THREADS {
+            rx.recv().unwrap();
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn fifo() {
+    let notify = Notify::new();
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for i in 0..THREADS {
+            let tx = tx.clone();
+            let notify = &notify;
+
+            scope.spawn(move |_| {
+                notify.wait();
+                tx.send(i).unwrap();
+            });
+
+            // Give the thread time to start waiting before spawning the next one.
+            sleep(ms(50));
+        }
+
+        for i in 0..THREADS {
+            assert!(notify.notify_one());
+            assert_eq!(rx.recv().unwrap(), i);
+        }
+    })
+    .unwrap();
+}
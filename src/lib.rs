@@ -18,6 +18,7 @@
 //! ## Thread synchronization
 //!
 //! * [`channel`], multi-producer multi-consumer channels for message passing.
+//! * [`Notify`], for waking up threads blocked waiting for a notification.
 //! * [`Parker`], a thread parking primitive.
 //! * [`ShardedLock`], a sharded reader-writer lock with fast concurrent reads.
 //! * [`WaitGroup`], for synchronizing the beginning or end of some computation.
@@ -34,6 +35,7 @@
 //! [`ArrayQueue`]: queue/struct.ArrayQueue.html
 //! [`SegQueue`]: queue/struct.SegQueue.html
 //! [`channel`]: channel/index.html
+//! [`Notify`]: sync/struct.Notify.html
 //! [`Parker`]: sync/struct.Parker.html
 //! [`ShardedLock`]: sync/struct.ShardedLock.html
 //! [`WaitGroup`]: sync/struct.WaitGroup.html